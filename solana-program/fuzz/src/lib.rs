@@ -0,0 +1,49 @@
+//! Shared fuzzing helpers for `fuzz_targets/process_instruction.rs`.
+//!
+//! Account references are modeled as small indices into a pool of generated
+//! `Pubkey`s (Trident's approach) rather than raw random 32-byte arrays, so a
+//! run actually exercises cross-account authorization paths - e.g.
+//! `workspace.organization` matching a real, pool-backed `Organization` -
+//! instead of failing every single time on a mismatched key.
+
+use arbitrary::{Arbitrary, Unstructured};
+use solana_program::pubkey::Pubkey;
+
+/// Number of distinct keys the fuzzer can reference. Small enough that
+/// `Arbitrary`-derived indices collide often, which is what exercises the
+/// "these two accounts agree on a key" paths instead of only the
+/// "definitely mismatched" ones.
+pub const POOL_SIZE: usize = 8;
+
+/// An index into a `KeyPool`, standing in for a raw `Pubkey` in fuzz-generated
+/// state structs so generated accounts can reference each other (or
+/// themselves) instead of always producing unrelated random keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountId(pub u8);
+
+impl<'a> Arbitrary<'a> for AccountId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AccountId(u.int_in_range(0..=(POOL_SIZE as u8 - 1))?))
+    }
+}
+
+/// A fixed pool of deterministic-from-seed keys that `AccountId`s resolve
+/// against, built once per fuzz input.
+pub struct KeyPool {
+    keys: [Pubkey; POOL_SIZE],
+}
+
+impl KeyPool {
+    pub fn generate(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut keys = [Pubkey::default(); POOL_SIZE];
+        for key in keys.iter_mut() {
+            let bytes: [u8; 32] = u.arbitrary()?;
+            *key = Pubkey::new_from_array(bytes);
+        }
+        Ok(Self { keys })
+    }
+
+    pub fn resolve(&self, id: AccountId) -> Pubkey {
+        self.keys[id.0 as usize]
+    }
+}
@@ -0,0 +1,259 @@
+//! RPC/explorer-friendly JSON views of Veriflow accounts, mirroring Solana's
+//! `account-decoder` (`UiAccount`/`parse_account_data`). Off-chain only:
+//! gated behind the `decoder` feature so the BPF build never pulls in serde.
+//!
+//! `Pubkey`s render as base58, `[u8; 32]` hashes as hex, and `i64`
+//! timestamps as strings, since a JS `Number` can't round-trip a full `i64`.
+
+use borsh::BorshDeserialize;
+use serde::Serialize;
+use solana_program::pubkey::Pubkey;
+use thiserror::Error;
+
+use crate::state::{
+    ExecutionLog, Organization, PRStatus, PullRequest, VersionCommit, Workspace, CURRENT_SCHEMA_VERSION,
+};
+
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("account data length {0} does not match any known Veriflow account layout")]
+    UnknownAccountType(usize),
+
+    #[error("failed to deserialize account data: {0}")]
+    Deserialize(#[from] std::io::Error),
+
+    #[error("account schema version {0} is newer than this decoder understands")]
+    UnsupportedSchemaVersion(u8),
+}
+
+fn to_base58(pubkey: &Pubkey) -> String {
+    bs58::encode(pubkey.to_bytes()).into_string()
+}
+
+fn to_hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UiOrganization {
+    pub schema_version: u8,
+    pub is_initialized: bool,
+    pub owner: String,
+    pub created_at: String,
+    pub workspace_count: String,
+}
+
+impl From<Organization> for UiOrganization {
+    fn from(org: Organization) -> Self {
+        Self {
+            schema_version: org.schema_version,
+            is_initialized: org.is_initialized,
+            owner: to_base58(&org.owner),
+            created_at: org.created_at.to_string(),
+            workspace_count: org.workspace_count.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UiApprovalPolicy {
+    pub required_approvals: u8,
+    pub reviewers: Vec<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UiWorkspace {
+    pub schema_version: u8,
+    pub is_initialized: bool,
+    pub organization: String,
+    pub creator: String,
+    pub current_version: String,
+    pub current_state_root: String,
+    pub mmr_peaks: Vec<String>,
+    pub mmr_leaf_count: String,
+    pub approval_policy: UiApprovalPolicy,
+    pub parent_workspace: Option<String>,
+    pub fork_at_version: Option<String>,
+    pub created_at: String,
+}
+
+impl From<Workspace> for UiWorkspace {
+    fn from(workspace: Workspace) -> Self {
+        Self {
+            schema_version: workspace.schema_version,
+            is_initialized: workspace.is_initialized,
+            organization: to_base58(&workspace.organization),
+            creator: to_base58(&workspace.creator),
+            current_version: workspace.current_version.to_string(),
+            current_state_root: to_hex(&workspace.current_state_root),
+            mmr_peaks: workspace.mmr_peaks.iter().map(to_hex).collect(),
+            mmr_leaf_count: workspace.mmr_leaf_count.to_string(),
+            approval_policy: UiApprovalPolicy {
+                required_approvals: workspace.approval_policy.required_approvals,
+                reviewers: workspace.approval_policy.reviewers.iter().map(to_base58).collect(),
+            },
+            parent_workspace: workspace.parent_workspace.as_ref().map(to_base58),
+            fork_at_version: workspace.fork_at_version.map(|v| v.to_string()),
+            created_at: workspace.created_at.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UiVersionCommit {
+    pub schema_version: u8,
+    pub is_initialized: bool,
+    pub workspace: String,
+    pub version_number: String,
+    pub content_hash: String,
+    pub parent_hashes: Vec<String>,
+    pub author: String,
+    pub timestamp: String,
+    pub message: String,
+    pub wormhole_sequence: Option<String>,
+}
+
+impl From<VersionCommit> for UiVersionCommit {
+    fn from(commit: VersionCommit) -> Self {
+        Self {
+            schema_version: commit.schema_version,
+            is_initialized: commit.is_initialized,
+            workspace: to_base58(&commit.workspace),
+            version_number: commit.version_number.to_string(),
+            content_hash: to_hex(&commit.content_hash),
+            parent_hashes: commit.parent_hashes[..commit.parent_count as usize]
+                .iter()
+                .map(to_hex)
+                .collect(),
+            author: to_base58(&commit.author),
+            timestamp: commit.timestamp.to_string(),
+            message: commit.message,
+            wormhole_sequence: commit.wormhole_sequence.map(|s| s.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UiPullRequest {
+    pub schema_version: u8,
+    pub is_initialized: bool,
+    pub source_workspace: String,
+    pub target_workspace: String,
+    pub source_version_hash: String,
+    pub target_version_hash: String,
+    pub proposer: String,
+    pub reviewer: Option<String>,
+    pub status: PRStatus,
+    pub created_at: String,
+    pub reviewed_at: Option<String>,
+    pub approvals: u8,
+    pub rejections: u8,
+    pub merge_instruction_count: usize,
+    pub executed: bool,
+}
+
+impl From<PullRequest> for UiPullRequest {
+    fn from(pr: PullRequest) -> Self {
+        Self {
+            schema_version: pr.schema_version,
+            is_initialized: pr.is_initialized,
+            source_workspace: to_base58(&pr.source_workspace),
+            target_workspace: to_base58(&pr.target_workspace),
+            source_version_hash: to_hex(&pr.source_version_hash),
+            target_version_hash: to_hex(&pr.target_version_hash),
+            proposer: to_base58(&pr.proposer),
+            reviewer: pr.reviewer.as_ref().map(to_base58),
+            status: pr.status,
+            created_at: pr.created_at.to_string(),
+            reviewed_at: pr.reviewed_at.map(|t| t.to_string()),
+            approvals: pr.approvals,
+            rejections: pr.rejections,
+            merge_instruction_count: pr.merge_instructions.len(),
+            executed: pr.executed,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UiExecutionLog {
+    pub schema_version: u8,
+    pub is_initialized: bool,
+    pub workspace: String,
+    pub executor: String,
+    pub version_hash: String,
+    pub result_hash: String,
+    pub timestamp: String,
+}
+
+impl From<ExecutionLog> for UiExecutionLog {
+    fn from(log: ExecutionLog) -> Self {
+        Self {
+            schema_version: log.schema_version,
+            is_initialized: log.is_initialized,
+            workspace: to_base58(&log.workspace),
+            executor: to_base58(&log.executor),
+            version_hash: to_hex(&log.version_hash),
+            result_hash: to_hex(&log.result_hash),
+            timestamp: log.timestamp.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum UiVeriflowAccount {
+    Organization(UiOrganization),
+    Workspace(UiWorkspace),
+    VersionCommit(UiVersionCommit),
+    PullRequest(UiPullRequest),
+    ExecutionLog(UiExecutionLog),
+}
+
+fn check_schema_version(data: &[u8]) -> Result<(), ParseError> {
+    let version = *data.first().ok_or(ParseError::UnknownAccountType(data.len()))?;
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(ParseError::UnsupportedSchemaVersion(version));
+    }
+    Ok(())
+}
+
+/// Decode a raw Veriflow account buffer into its stable JSON view.
+///
+/// The account's full, length-padded data (as returned by an RPC
+/// `getAccountInfo` call) is discriminated by its length, since Veriflow
+/// never shares a single `LEN` across two account kinds. There's no on-chain
+/// type tag to dispatch on otherwise - `account_kind`-aware callers that
+/// already know which PDA they fetched can skip the guesswork by calling
+/// the matching `Ui*::from` conversion directly instead.
+pub fn parse_veriflow_account(data: &[u8]) -> Result<UiVeriflowAccount, ParseError> {
+    check_schema_version(data)?;
+
+    // Accounts are allocated at a fixed `T::LEN` and never shrink, so `data`
+    // almost always has trailing bytes past whatever the value actually
+    // serialized to. `try_from_slice` rejects that as "Not all bytes read" -
+    // use a reader that only consumes what the value needs instead.
+    match data.len() {
+        Organization::LEN => Ok(UiVeriflowAccount::Organization(
+            Organization::deserialize(&mut &data[..])?.into(),
+        )),
+        Workspace::LEN => Ok(UiVeriflowAccount::Workspace(
+            Workspace::deserialize(&mut &data[..])?.into(),
+        )),
+        VersionCommit::LEN => Ok(UiVeriflowAccount::VersionCommit(
+            VersionCommit::deserialize(&mut &data[..])?.into(),
+        )),
+        PullRequest::LEN => Ok(UiVeriflowAccount::PullRequest(
+            PullRequest::deserialize(&mut &data[..])?.into(),
+        )),
+        ExecutionLog::LEN => Ok(UiVeriflowAccount::ExecutionLog(
+            ExecutionLog::deserialize(&mut &data[..])?.into(),
+        )),
+        other => Err(ParseError::UnknownAccountType(other)),
+    }
+}
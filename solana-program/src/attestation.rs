@@ -0,0 +1,116 @@
+//! Canonical wire format for cross-chain state-root attestations, following
+//! Wormhole's approach of a universal chain-id enum and a compact,
+//! fixed-layout payload. The payload is consumed by off-chain relayers and
+//! other chains as raw bytes, so it is hand-encoded rather than Borsh, and
+//! multi-byte fields are big-endian to match that convention.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::error::VeriflowError;
+
+/// This program only ever runs on Solana, so every attestation it emits
+/// carries this as `emitter_chain`.
+pub const EMITTER_CHAIN_ID: u16 = 1;
+
+pub const PAYLOAD_VERSION: u8 = 1;
+
+pub const PAYLOAD_LEN: usize = 1 + 2 + 32 + 8 + 32 + 8;
+
+/// Universal chain-id space an attestation can be routed to, mirroring
+/// Wormhole's chain registry. Not stored in the payload itself - it only
+/// selects which dedicated attestation account a given emission lands in, so
+/// relayers for different chains don't contend over the same PDA.
+#[repr(u16)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetChain {
+    Ethereum = 2,
+    Bsc = 4,
+    Polygon = 5,
+    Avalanche = 6,
+    Arbitrum = 23,
+}
+
+impl TargetChain {
+    pub fn id(self) -> u16 {
+        self as u16
+    }
+}
+
+/// Fixed-layout state-root attestation:
+/// `payload_version(1) || emitter_chain(2, BE) || workspace(32) ||
+/// current_version(8, BE) || state_root(32) || timestamp(8, BE)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateAttestation {
+    pub payload_version: u8,
+    pub emitter_chain: u16,
+    pub workspace: [u8; 32],
+    pub current_version: u64,
+    pub state_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+impl StateAttestation {
+    pub fn encode(&self) -> [u8; PAYLOAD_LEN] {
+        let mut buf = [0u8; PAYLOAD_LEN];
+        let mut offset = 0;
+
+        buf[offset] = self.payload_version;
+        offset += 1;
+
+        buf[offset..offset + 2].copy_from_slice(&self.emitter_chain.to_be_bytes());
+        offset += 2;
+
+        buf[offset..offset + 32].copy_from_slice(&self.workspace);
+        offset += 32;
+
+        buf[offset..offset + 8].copy_from_slice(&self.current_version.to_be_bytes());
+        offset += 8;
+
+        buf[offset..offset + 32].copy_from_slice(&self.state_root);
+        offset += 32;
+
+        buf[offset..offset + 8].copy_from_slice(&self.timestamp.to_be_bytes());
+
+        buf
+    }
+}
+
+/// Decode and validate a `StateAttestation` payload, rejecting anything with
+/// the wrong length or an unrecognized `payload_version` so the same format
+/// round-trips on the receiving side.
+pub fn parse_attestation(data: &[u8]) -> Result<StateAttestation, ProgramError> {
+    if data.len() != PAYLOAD_LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let payload_version = data[0];
+    if payload_version != PAYLOAD_VERSION {
+        return Err(VeriflowError::UnsupportedSchemaVersion.into());
+    }
+
+    let mut offset = 1;
+
+    let emitter_chain = u16::from_be_bytes(data[offset..offset + 2].try_into().unwrap());
+    offset += 2;
+
+    let workspace: [u8; 32] = data[offset..offset + 32].try_into().unwrap();
+    offset += 32;
+
+    let current_version = u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
+    offset += 8;
+
+    let state_root: [u8; 32] = data[offset..offset + 32].try_into().unwrap();
+    offset += 32;
+
+    let timestamp = i64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
+
+    Ok(StateAttestation {
+        payload_version,
+        emitter_chain,
+        workspace,
+        current_version,
+        state_root,
+        timestamp,
+    })
+}
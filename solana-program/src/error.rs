@@ -26,6 +26,27 @@ pub enum VeriflowError {
 
     #[error("Invalid PR state")]
     InvalidPRState,
+
+    #[error("Target program is not executable")]
+    TargetNotExecutable,
+
+    #[error("CPI call returned no data")]
+    MissingReturnData,
+
+    #[error("Account schema version is newer than this program understands")]
+    UnsupportedSchemaVersion,
+
+    #[error("Invalid approval policy")]
+    InvalidApprovalPolicy,
+
+    #[error("Invalid merge instructions")]
+    InvalidMergeInstructions,
+
+    #[error("Merge instructions already executed")]
+    AlreadyExecuted,
+
+    #[error("Provided accounts do not match the merge instruction's expected accounts")]
+    MergeAccountMismatch,
 }
 
 impl From<VeriflowError> for ProgramError {
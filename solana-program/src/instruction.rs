@@ -1,4 +1,8 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::attestation::TargetChain;
+use crate::state::{AccountKind, InstructionData};
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub enum VeriflowInstruction {
@@ -57,23 +61,29 @@ pub enum VeriflowInstruction {
         title: String,
         source_version_hash: [u8; 32],
         target_version_hash: [u8; 32],
+        /// CPI calls to replay via `ExecuteMergedVersion` once this PR merges.
+        merge_instructions: Vec<InstructionData>,
     },
 
-    /// Approve a pull request
+    /// Approve a pull request under the workspace's default, single-reviewer
+    /// ApprovalPolicy. Rejected once a workspace configures an actual M-of-N
+    /// policy via SetApprovalPolicy - use CastReview instead so the vote is
+    /// tallied against it.
     /// Accounts:
     /// 0. [signer] Reviewer
     /// 1. [writable] Pull Request PDA
-    /// 2. [] Organization PDA
+    /// 2. [] Target Workspace PDA
     ApprovePullRequest,
 
     /// Merge a pull request
     /// Accounts:
     /// 0. [signer] Merger (owner/reviewer)
     /// 1. [writable] Pull Request PDA
-    /// 2. [writable] Target Workspace PDA
-    /// 3. [writable] New Version Commit PDA
-    /// 4. [] Organization PDA
-    /// 5. [] System program
+    /// 2. [] Source Workspace PDA (re-checked against pr.source_version_hash)
+    /// 3. [writable] Target Workspace PDA
+    /// 4. [writable] New Version Commit PDA
+    /// 5. [] Organization PDA
+    /// 6. [] System program
     MergePullRequest {
         merge_commit_hash: [u8; 32],
         message: String,
@@ -89,4 +99,132 @@ pub enum VeriflowInstruction {
         version_hash: [u8; 32],
         result_hash: [u8; 32],
     },
+
+    /// Invoke a registered downstream workflow program via CPI and record the
+    /// on-chain-verified outcome, instead of trusting a caller-supplied result_hash.
+    /// Accounts:
+    /// 0. [signer] Executor
+    /// 1. [] Workspace PDA
+    /// 2. [writable] Execution Log PDA
+    /// 3. [] System program
+    /// 4. [executable] Target workflow program
+    /// 5..N. Accounts required by the target program, forwarded as-is
+    ExecuteAndRecord {
+        version_hash: [u8; 32],
+        input_data: Vec<u8>,
+    },
+
+    /// Post a cross-chain attestation of a committed version through the Wormhole
+    /// core bridge, so the version's proof can be verified on other chains.
+    /// Accounts:
+    /// 0. [signer] Attester
+    /// 1. [] Workspace PDA
+    /// 2. [writable] Version Commit PDA (updated with the returned sequence number)
+    /// 3. [] Wormhole emitter PDA (derived from ATTEST_SEED + workspace)
+    /// 4. [writable] Wormhole bridge config
+    /// 5. [writable, signer] Wormhole message account (fresh keypair)
+    /// 6. [writable] Wormhole fee collector
+    /// 7. [] Wormhole core bridge program
+    /// 8. [] Clock sysvar
+    /// 9. [] Rent sysvar
+    /// 10. [] System program
+    AttestVersion {
+        nonce: u32,
+        consistency_level: u8,
+    },
+
+    /// Prove that a version at `leaf_index` is included under the workspace's
+    /// current Merkle Mountain Range root, without replaying the whole history.
+    /// Accounts:
+    /// 0. [] Workspace PDA
+    VerifyInclusion {
+        leaf_index: u64,
+        leaf_hash: [u8; 32],
+        /// Sibling hashes from the leaf up to its MMR peak, bottom to top.
+        siblings: Vec<[u8; 32]>,
+        /// For each sibling, whether it sits to the right of the running node.
+        sibling_is_right: Vec<bool>,
+        /// Index of the recomputed peak within the full peaks array.
+        peak_position: u8,
+        /// All other (already-known) peaks, in left-to-right order.
+        other_peaks: Vec<[u8; 32]>,
+    },
+
+    /// Reallocate an account to the current layout size and rewrite it under
+    /// `CURRENT_SCHEMA_VERSION`, so accounts created before a layout change
+    /// don't get orphaned.
+    /// Accounts:
+    /// 0. [writable, signer] Authority (pays for any added rent)
+    /// 1. [writable] Account to migrate
+    /// 2. [] System program
+    MigrateAccount {
+        account_kind: AccountKind,
+    },
+
+    /// Prove that `version_account`'s commit is included under the workspace's
+    /// current root, re-deriving the leaf from the on-chain VersionCommit
+    /// itself rather than trusting caller-supplied version/content fields.
+    /// Accounts:
+    /// 0. [] Workspace PDA
+    /// 1. [] Version Commit PDA
+    VerifyVersionInclusion {
+        /// Sibling hashes from the leaf up to its MMR peak, bottom to top.
+        siblings: Vec<[u8; 32]>,
+        /// For each sibling, whether it sits to the right of the running node.
+        directions: Vec<bool>,
+        /// Index of the recomputed peak within the full peaks array.
+        peak_position: u8,
+        /// All other (already-known) peaks, in left-to-right order.
+        other_peaks: Vec<[u8; 32]>,
+    },
+
+    /// Configure the M-of-N reviewer set required to approve PRs targeting
+    /// this workspace.
+    /// Accounts:
+    /// 0. [signer] Creator
+    /// 1. [writable] Workspace PDA
+    SetApprovalPolicy {
+        required_approvals: u8,
+        reviewers: Vec<Pubkey>,
+    },
+
+    /// Cast a one-vote-per-reviewer Approve/Reject on a PR. Flips `PRStatus`
+    /// to `Approved` once the workspace's threshold is met, or to `Rejected`
+    /// once enough rejections make approval impossible.
+    /// Accounts:
+    /// 0. [writable, signer] Reviewer
+    /// 1. [writable] Pull Request PDA
+    /// 2. [] Target Workspace PDA (holds the ApprovalPolicy)
+    /// 3. [writable] Review Vote PDA (seeds [VOTE_SEED, pr_pubkey, reviewer_pubkey])
+    /// 4. [] System program
+    CastReview {
+        approve: bool,
+    },
+
+    /// Write a canonical, version-prefixed state-root attestation payload to
+    /// a dedicated account and log it via `sol_log_data` so an off-chain
+    /// relayer/guardian set can pick it up and relay it to `target_chain`.
+    /// Accounts:
+    /// 0. [writable, signer] Attester (pays for account creation)
+    /// 1. [] Workspace PDA
+    /// 2. [writable] Attestation PDA (seeds [STATE_ATTEST_SEED, workspace_pubkey, target_chain_id_le, current_version_le])
+    /// 3. [] System program
+    EmitStateAttestation {
+        target_chain: TargetChain,
+    },
+
+    /// Replay a merged PR's attached `merge_instructions` via `invoke_signed`,
+    /// using the workspace's execution authority PDA as signer, and record
+    /// the outcome. Only runs once (guarded by `PullRequest::executed`) and
+    /// only after `PRStatus::Merged`.
+    /// Accounts:
+    /// 0. [writable, signer] Executor (pays for the Execution Log account; need not be the merger)
+    /// 1. [writable] Pull Request PDA
+    /// 2. [] Target Workspace PDA
+    /// 3. [] Execution authority PDA (seeds [EXEC_AUTHORITY_SEED, workspace_pubkey])
+    /// 4. [writable] Execution Log PDA
+    /// 5. [] System program
+    /// 6..N. Every account referenced by `merge_instructions`, concatenated in order,
+    ///       followed by each instruction's own program account
+    ExecuteMergedVersion,
 }
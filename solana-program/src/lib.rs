@@ -18,6 +18,10 @@ pub mod instruction;
 pub mod state;
 pub mod processor;
 pub mod error;
+pub mod merkle;
+pub mod attestation;
+#[cfg(feature = "decoder")]
+pub mod account_decoder;
 
 // Re-export for convenience
 pub use instruction::VeriflowInstruction;
@@ -0,0 +1,106 @@
+//! Domain-separated Merkle hashing shared by the MMR accumulator
+//! (`processor::mmr_append`/`mmr_bag`) and `VerifyVersionInclusion`.
+//!
+//! Leaves and internal nodes are tagged with distinct prefix bytes so a leaf
+//! can never be replayed as an internal node (or vice versa) to forge a proof.
+
+use solana_program::keccak;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Hash a committed version into a leaf: `H(0x00 || version_number_le || content_hash)`.
+pub fn hash_leaf(version_number: u64, content_hash: &[u8; 32]) -> [u8; 32] {
+    keccak::hashv(&[&[LEAF_PREFIX], &version_number.to_le_bytes(), content_hash]).to_bytes()
+}
+
+/// Hash two nodes together: `H(0x01 || left || right)`.
+pub fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    keccak::hashv(&[&[NODE_PREFIX], left, right]).to_bytes()
+}
+
+/// Build a full binary Merkle tree bottom-up over `leaves`, duplicating the
+/// last node at any level with an odd count. An empty tree bags to the zero
+/// hash; a single leaf bags to itself.
+pub fn compute_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut level = match leaves {
+        [] => return [0u8; 32],
+        leaves => leaves.to_vec(),
+    };
+
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(*level.last().unwrap());
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| hash_node(&pair[0], &pair[1]))
+            .collect();
+    }
+
+    level[0]
+}
+
+/// Recompute a root by climbing from `leaf` through its authentication path.
+/// `directions[i] == true` means `siblings[i]` sits to the right of the
+/// running node at that step.
+pub fn recompute_root(leaf: [u8; 32], siblings: &[[u8; 32]], directions: &[bool]) -> [u8; 32] {
+    let mut node = leaf;
+    for (sibling, is_right) in siblings.iter().zip(directions.iter()) {
+        node = if *is_right {
+            hash_node(&node, sibling)
+        } else {
+            hash_node(sibling, &node)
+        };
+    }
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaf_and_node_hashes_are_domain_separated() {
+        // Same bytes, different prefix - a leaf must never double as a node.
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        assert_ne!(hash_leaf(0, &a), hash_node(&a, &b));
+        assert_ne!(hash_node(&a, &b), hash_node(&b, &a));
+    }
+
+    #[test]
+    fn compute_root_empty_and_single_leaf() {
+        assert_eq!(compute_root(&[]), [0u8; 32]);
+
+        let leaf = hash_leaf(1, &[7u8; 32]);
+        assert_eq!(compute_root(&[leaf]), leaf);
+    }
+
+    #[test]
+    fn compute_root_duplicates_the_odd_node_out() {
+        let a = hash_leaf(1, &[1u8; 32]);
+        let b = hash_leaf(2, &[2u8; 32]);
+        let c = hash_leaf(3, &[3u8; 32]);
+
+        // Three leaves: c gets duplicated to pair with itself at the first level.
+        let expected = hash_node(&hash_node(&a, &b), &hash_node(&c, &c));
+        assert_eq!(compute_root(&[a, b, c]), expected);
+    }
+
+    #[test]
+    fn recompute_root_round_trips_against_compute_root() {
+        let leaves: Vec<[u8; 32]> = (0..4)
+            .map(|i| hash_leaf(i, &[i as u8; 32]))
+            .collect();
+        let root = compute_root(&leaves);
+
+        // Authentication path for leaves[1]: sibling leaves[0] (on the left),
+        // then the node bagging (leaves[2], leaves[3]) (on the right).
+        let sibling_pair = hash_node(&leaves[2], &leaves[3]);
+        let siblings = [leaves[0], sibling_pair];
+        let directions = [false, true];
+
+        assert_eq!(recompute_root(leaves[1], &siblings, &directions), root);
+    }
+}
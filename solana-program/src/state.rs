@@ -1,58 +1,151 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+// `Pubkey` has no `Arbitrary` impl of its own (it's a foreign type from a
+// foreign crate, so we can't add one), so every fuzz-derived struct below
+// that embeds one points its `Pubkey`/`Option<Pubkey>`/`Vec<Pubkey>` fields
+// at these helpers via `#[arbitrary(with = ...)]` instead - the same
+// draw-32-bytes-and-convert trick `fuzz/src/lib.rs`'s `KeyPool` uses.
+#[cfg(feature = "fuzz")]
+fn arbitrary_pubkey(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Pubkey> {
+    Ok(Pubkey::new_from_array(u.arbitrary()?))
+}
+
+#[cfg(feature = "fuzz")]
+fn arbitrary_option_pubkey(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Option<Pubkey>> {
+    use arbitrary::Arbitrary;
+    Ok(if bool::arbitrary(u)? { Some(arbitrary_pubkey(u)?) } else { None })
+}
+
+#[cfg(feature = "fuzz")]
+fn arbitrary_reviewers(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Vec<Pubkey>> {
+    let len = u.int_in_range(0..=MAX_REVIEWERS)?;
+    (0..len).map(|_| arbitrary_pubkey(u)).collect()
+}
+
 // PDA Seeds
 pub const ORG_SEED: &[u8] = b"org";
 pub const WORKSPACE_SEED: &[u8] = b"workspace";
 pub const VERSION_SEED: &[u8] = b"version";
 pub const PR_SEED: &[u8] = b"pr";
 pub const EXECUTION_SEED: &[u8] = b"execution";
+pub const ATTEST_SEED: &[u8] = b"attest";
+pub const VOTE_SEED: &[u8] = b"vote";
+pub const STATE_ATTEST_SEED: &[u8] = b"state_attest";
+pub const EXEC_AUTHORITY_SEED: &[u8] = b"exec_authority";
+
+/// A u64 leaf count can never produce more than 64 MMR peaks (one per bit).
+pub const MAX_MMR_PEAKS: usize = 64;
+
+/// Upper bound on reviewers in a workspace's ApprovalPolicy, sized into `Workspace::LEN`.
+pub const MAX_REVIEWERS: usize = 20;
+
+/// M-of-N reviewer policy governing when a PullRequest can move to `Approved`.
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct ApprovalPolicy {
+    pub required_approvals: u8,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_reviewers))]
+    pub reviewers: Vec<Pubkey>,
+}
+
+impl ApprovalPolicy {
+    pub const LEN: usize = 1 + (4 + MAX_REVIEWERS * 32);
+
+    /// Single-reviewer default, matching the workspace's original approval model.
+    pub fn default_single_reviewer() -> Self {
+        Self {
+            required_approvals: 1,
+            reviewers: Vec::new(),
+        }
+    }
+}
+
+/// Current on-chain layout version for every state struct below. Bump this
+/// whenever a struct gains/loses fields, and add the corresponding decode arm
+/// to `processor::process_migrate_account` so older accounts can be upgraded.
+pub const CURRENT_SCHEMA_VERSION: u8 = 1;
 
+/// Identifies which state struct an account holds, for `MigrateAccount`
+/// (raw account data carries no type tag of its own).
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum AccountKind {
+    Organization,
+    Workspace,
+    VersionCommit,
+    PullRequest,
+    ExecutionLog,
+}
+
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Organization {
+    pub schema_version: u8,
     pub is_initialized: bool,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub owner: Pubkey,
     pub created_at: i64,
     pub workspace_count: u64,
 }
 
 impl Organization {
-    pub const LEN: usize = 1 + 32 + 8 + 8; // bool + pubkey + i64 + u64
+    pub const LEN: usize = 1 + 1 + 32 + 8 + 8; // schema + bool + pubkey + i64 + u64
 }
 
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Workspace {
+    pub schema_version: u8,
     pub is_initialized: bool,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub organization: Pubkey,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub creator: Pubkey,
     pub current_version: u64,
-    pub current_state_root: [u8; 32], // Merkle root of version history
+    pub current_state_root: [u8; 32], // Bagged root of the version history MMR
+    pub mmr_peaks: Vec<[u8; 32]>, // MMR peaks, left (tallest) to right (shortest)
+    pub mmr_leaf_count: u64, // Number of versions appended to the MMR so far
+    pub approval_policy: ApprovalPolicy,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_option_pubkey))]
     pub parent_workspace: Option<Pubkey>, // None for main, Some for forks
     pub fork_at_version: Option<u64>,
     pub created_at: i64,
 }
 
 impl Workspace {
-    pub const LEN: usize = 1 + 32 + 32 + 8 + 32 + (1 + 32) + (1 + 8) + 8;
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 8 + 32 + (4 + MAX_MMR_PEAKS * 32) + 8
+        + ApprovalPolicy::LEN + (1 + 32) + (1 + 8) + 8;
 }
 
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct VersionCommit {
+    pub schema_version: u8,
     pub is_initialized: bool,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub workspace: Pubkey,
     pub version_number: u64,
     pub content_hash: [u8; 32],
-    pub parent_hash: [u8; 32], // Hash of previous version (forms chain)
+    // Parent version hashes: one for a linear commit, two for a merge (target-side
+    // first, then source-side), forming a git-style DAG rather than a single chain.
+    pub parent_hashes: [[u8; 32]; Self::MAX_PARENTS],
+    pub parent_count: u8,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub author: Pubkey,
     pub timestamp: i64,
     pub message: String, // Max 64 chars to keep size reasonable
+    pub wormhole_sequence: Option<u64>, // Set once AttestVersion posts this version cross-chain
 }
 
 impl VersionCommit {
     pub const MAX_MESSAGE_LEN: usize = 64;
-    pub const LEN: usize = 1 + 32 + 8 + 32 + 32 + 32 + 8 + 4 + Self::MAX_MESSAGE_LEN;
+    pub const MAX_PARENTS: usize = 4;
+    pub const LEN: usize =
+        1 + 1 + 32 + 8 + 32 + (Self::MAX_PARENTS * 32) + 1 + 32 + 8 + 4 + Self::MAX_MESSAGE_LEN + (1 + 8);
 }
 
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "decoder", derive(serde::Serialize))]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
 pub enum PRStatus {
     Open,
@@ -61,28 +154,110 @@ pub enum PRStatus {
     Rejected,
 }
 
+/// Upper bound on `InstructionData`s a single PR can attach, sized into
+/// `PullRequest::LEN`.
+pub const MAX_MERGE_INSTRUCTIONS: usize = 4;
+
+/// Upper bound on accounts referenced by a single `InstructionData`.
+pub const MAX_INSTRUCTION_ACCOUNTS: usize = 10;
+
+/// Upper bound on an `InstructionData`'s opaque `data` payload.
+pub const MAX_INSTRUCTION_DATA_LEN: usize = 256;
+
+/// Borsh-friendly mirror of `solana_program::instruction::AccountMeta`,
+/// since the real type doesn't derive `BorshSerialize`/`BorshDeserialize`.
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct AccountMetaData {
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl AccountMetaData {
+    pub const LEN: usize = 32 + 1 + 1;
+}
+
+/// A single CPI call attached to a PR, replayed by `ExecuteMergedVersion`
+/// once the PR has merged - the executable-instruction pattern from
+/// spl-governance's `ProposalInstruction`.
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct InstructionData {
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
+    pub program_id: Pubkey,
+    pub accounts: Vec<AccountMetaData>,
+    pub data: Vec<u8>,
+}
+
+impl InstructionData {
+    pub const LEN: usize = 32
+        + (4 + MAX_INSTRUCTION_ACCOUNTS * AccountMetaData::LEN)
+        + (4 + MAX_INSTRUCTION_DATA_LEN);
+}
+
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct PullRequest {
+    pub schema_version: u8,
     pub is_initialized: bool,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub source_workspace: Pubkey,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub target_workspace: Pubkey,
     pub source_version_hash: [u8; 32],
     pub target_version_hash: [u8; 32],
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub proposer: Pubkey,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_option_pubkey))]
     pub reviewer: Option<Pubkey>,
     pub status: PRStatus,
     pub created_at: i64,
     pub reviewed_at: Option<i64>,
+    pub approvals: u8, // Votes tallied by CastReview against the workspace's ApprovalPolicy
+    pub rejections: u8,
+    // Instructions this PR's merge authorizes; replayed once by
+    // ExecuteMergedVersion after PRStatus::Merged.
+    pub merge_instructions: Vec<InstructionData>,
+    pub executed: bool,
 }
 
 impl PullRequest {
-    pub const LEN: usize = 1 + 32 + 32 + 32 + 32 + 32 + (1 + 32) + 1 + 8 + (1 + 8);
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 32 + 32 + 32 + (1 + 32) + 1 + 8 + (1 + 8) + 1 + 1
+        + (4 + MAX_MERGE_INSTRUCTIONS * InstructionData::LEN) + 1;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub enum ReviewVoteChoice {
+    Approve,
+    Reject,
+}
+
+/// One reviewer's one-vote-per-PR record, keyed by PDA so a reviewer cannot
+/// cast twice: seeds `[VOTE_SEED, pr_pubkey, reviewer_pubkey]`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct ReviewVote {
+    pub schema_version: u8,
+    pub is_initialized: bool,
+    pub pull_request: Pubkey,
+    pub reviewer: Pubkey,
+    pub choice: ReviewVoteChoice,
+    pub cast_at: i64,
+}
+
+impl ReviewVote {
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 1 + 8;
 }
 
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct ExecutionLog {
+    pub schema_version: u8,
     pub is_initialized: bool,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub workspace: Pubkey,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = arbitrary_pubkey))]
     pub executor: Pubkey,
     pub version_hash: [u8; 32],
     pub result_hash: [u8; 32],
@@ -90,5 +265,5 @@ pub struct ExecutionLog {
 }
 
 impl ExecutionLog {
-    pub const LEN: usize = 1 + 32 + 32 + 32 + 32 + 8;
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 32 + 32 + 8;
 }